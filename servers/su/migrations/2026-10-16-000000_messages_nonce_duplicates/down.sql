@@ -0,0 +1 @@
+-- The up migration only checks for duplicate nonces, nothing to undo
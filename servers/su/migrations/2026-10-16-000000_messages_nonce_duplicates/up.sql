@@ -0,0 +1,17 @@
+-- Fails before idx_messages_process_id_epoch_nonce is built in
+-- the next migration if a process already has two messages with
+-- the same epoch and nonce, a unique index could not be built
+-- over them. See Duplicate nonces in the README for finding and
+-- resolving them.
+DO $$
+DECLARE
+  duplicates BIGINT;
+BEGIN
+  SELECT COUNT(*) INTO duplicates FROM (
+    SELECT 1 FROM messages GROUP BY process_id, epoch, nonce HAVING COUNT(*) > 1
+  ) groups;
+  IF duplicates > 0 THEN
+    RAISE EXCEPTION '% (process_id, epoch, nonce) groups of messages have more than one row, resolve them before the unique nonce index can be built', duplicates;
+  END IF;
+END
+$$;
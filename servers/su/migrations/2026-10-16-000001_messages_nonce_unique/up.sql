@@ -0,0 +1 @@
+CREATE UNIQUE INDEX CONCURRENTLY idx_messages_process_id_epoch_nonce ON messages(process_id, epoch, nonce);
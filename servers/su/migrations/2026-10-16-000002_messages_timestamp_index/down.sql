@@ -0,0 +1 @@
+DROP INDEX CONCURRENTLY idx_messages_timestamp;
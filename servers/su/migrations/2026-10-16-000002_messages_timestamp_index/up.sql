@@ -0,0 +1 @@
+CREATE INDEX CONCURRENTLY idx_messages_timestamp ON messages(timestamp);
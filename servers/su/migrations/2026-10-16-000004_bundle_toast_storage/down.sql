@@ -0,0 +1 @@
+-- EXTENDED is already the default storage for bytea, nothing to undo
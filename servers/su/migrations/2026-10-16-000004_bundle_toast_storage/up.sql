@@ -0,0 +1,2 @@
+ALTER TABLE messages ALTER COLUMN bundle SET STORAGE EXTENDED;
+ALTER TABLE processes ALTER COLUMN bundle SET STORAGE EXTENDED;
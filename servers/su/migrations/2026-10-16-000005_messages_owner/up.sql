@@ -0,0 +1 @@
+ALTER TABLE messages ADD COLUMN owner VARCHAR(255) NULL;
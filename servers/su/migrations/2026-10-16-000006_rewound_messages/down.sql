@@ -0,0 +1 @@
+DROP TABLE rewound_messages;
@@ -0,0 +1,7 @@
+CREATE TABLE rewound_messages (
+  row_id SERIAL PRIMARY KEY,
+  process_id VARCHAR(255) NOT NULL,
+  rewound_at TIMESTAMP NOT NULL DEFAULT NOW(),
+  message JSONB NOT NULL
+);
+CREATE INDEX idx_rewound_messages_process_id ON rewound_messages(process_id);
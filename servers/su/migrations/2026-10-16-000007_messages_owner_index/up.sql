@@ -0,0 +1 @@
+CREATE INDEX CONCURRENTLY idx_messages_process_id_owner ON messages(process_id, owner);
@@ -0,0 +1 @@
+DROP INDEX CONCURRENTLY idx_messages_message_id_assignment_id;
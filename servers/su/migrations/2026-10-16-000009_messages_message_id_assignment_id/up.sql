@@ -0,0 +1,6 @@
+-- save_message and save_messages skip a resent message with
+-- ON CONFLICT (assignment_id), which relies on the UNIQUE
+-- constraint on assignment_id added in 2024-03-27-163751. This
+-- index states the (message_id, assignment_id) pair is unique
+-- too. It is built CONCURRENTLY so writes are not blocked.
+CREATE UNIQUE INDEX CONCURRENTLY idx_messages_message_id_assignment_id ON messages(message_id, assignment_id);
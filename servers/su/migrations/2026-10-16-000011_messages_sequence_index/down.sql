@@ -0,0 +1 @@
+DROP INDEX CONCURRENTLY idx_messages_process_id_sequence;
@@ -0,0 +1 @@
+CREATE INDEX CONCURRENTLY idx_messages_process_id_sequence ON messages(process_id, sequence);
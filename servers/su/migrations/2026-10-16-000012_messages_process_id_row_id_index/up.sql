@@ -0,0 +1 @@
+CREATE INDEX CONCURRENTLY idx_messages_process_id_row_id ON messages(process_id, row_id);
@@ -1,16 +1,39 @@
 use std::env;
 use std::io;
+use su::domain::backfill_owners;
+use su::domain::export_process;
 use su::domain::migrate_to_disk;
 use su::domain::migrate_to_local;
 use su::domain::sync_local_drives;
+use su::domain::train_dictionary;
+
+/*
+  With the structured-logs feature the cli logs through
+  tracing-subscriber, filtered by RUST_LOG and printed as
+  json when LOG_FORMAT=json.
+*/
+#[cfg(feature = "structured-logs")]
+fn init_tracing() {
+    use tracing_subscriber::EnvFilter;
+
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter);
+    match env::var("LOG_FORMAT").as_deref() {
+        Ok("json") => subscriber.json().init(),
+        _ => subscriber.init(),
+    }
+}
 
 #[tokio::main]
 async fn main() -> io::Result<()> {
+    #[cfg(feature = "structured-logs")]
+    init_tracing();
+
     let args: Vec<String> = env::args().collect();
 
     if args.len() < 2 {
         eprintln!("Usage: {} <function_name>", args[0]);
-        eprintln!("Available functions: migrate_to_disk, migrate_to_local, sync_local_drives");
+        eprintln!("Available functions: migrate_to_disk, migrate_to_local, sync_local_drives, backfill_owners, train_dictionary, export_process");
         return Ok(());
     }
 
@@ -36,9 +59,18 @@ async fn main() -> io::Result<()> {
         "sync_local_drives" => {
             sync_local_drives(interval).await.unwrap();
         }
+        "backfill_owners" => {
+            backfill_owners().await.unwrap();
+        }
+        "train_dictionary" => {
+            train_dictionary().await.unwrap();
+        }
+        "export_process" => {
+            export_process().await.unwrap();
+        }
         _ => {
             eprintln!("Invalid function name: {}", args[1]);
-            eprintln!("Available functions: migrate_to_disk, migrate_to_local, sync_local_drives");
+            eprintln!("Available functions: migrate_to_disk, migrate_to_local, sync_local_drives, backfill_owners, train_dictionary, export_process");
         }
     }
 
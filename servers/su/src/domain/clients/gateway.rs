@@ -1,5 +1,6 @@
 use crate::domain::config::AoConfig;
-use crate::domain::core::dal::{Gateway, GatewayTx, NetworkInfo, TxStatus};
+use crate::domain::core::dal::{Gateway, GatewayTx, Log, NetworkInfo, TxStatus};
+use crate::domain::SuLog;
 use arweave_rs::network::NetworkInfoClient;
 use async_trait::async_trait;
 use reqwest::{Client, Url};
@@ -12,6 +13,7 @@ pub struct ArweaveGateway {
     // Use RwLock to safely share and update state across tasks
     height: Arc<RwLock<String>>,
     current: Arc<RwLock<String>>,
+    logger: Arc<dyn Log>,
 }
 
 #[derive(Debug)]
@@ -50,7 +52,8 @@ struct GraphQLResponse {
 
 impl ArweaveGateway {
     pub async fn new() -> Result<Self, String> {
-        let network_info = ArweaveGateway::network_info_fetch().await?;
+        let logger = SuLog::init();
+        let network_info = ArweaveGateway::network_info_fetch(&logger).await?;
 
         let height = Arc::new(RwLock::new(network_info.height.clone()));
         let current = Arc::new(RwLock::new(network_info.current.clone()));
@@ -58,13 +61,14 @@ impl ArweaveGateway {
         let gateway = ArweaveGateway {
             height: height.clone(),
             current: current.clone(),
+            logger: logger.clone(),
         };
 
         // Spawn a background task to refresh network info every 1 minute
         tokio::spawn(async move {
             loop {
                 sleep(Duration::from_secs(5)).await;
-                if let Ok(updated_info) = ArweaveGateway::network_info_fetch().await {
+                if let Ok(updated_info) = ArweaveGateway::network_info_fetch(&logger).await {
                     let mut height_lock = height.write().await;
                     *height_lock = updated_info.height.clone();
                     let mut current_lock = current.write().await;
@@ -76,7 +80,7 @@ impl ArweaveGateway {
         Ok(gateway)
     }
 
-    async fn network_info_fetch() -> Result<NetworkInfo, String> {
+    async fn network_info_fetch(logger: &Arc<dyn Log>) -> Result<NetworkInfo, String> {
         let config = AoConfig::new(Some("su".to_string())).expect("Failed to read configuration");
         let arweave_url = config.arweave_url;
         let url = Url::parse(&arweave_url).map_err(|e| format!("{:?}", e))?;
@@ -96,10 +100,10 @@ impl ArweaveGateway {
                 }
                 Err(_) if attempt < 4 => {
                     // Log the failed attempt and wait before retrying
-                    println!(
+                    logger.error(format!(
                         "Attempt {}: Failed to fetch network info, retrying...",
                         attempt + 1
-                    );
+                    ));
                     sleep(Duration::from_secs(1)).await;
                 }
                 Err(e) => {
@@ -129,7 +133,8 @@ impl Gateway for ArweaveGateway {
             let url = match Url::parse(&arweave_url) {
                 Ok(u) => u,
                 Err(e) => {
-                    eprintln!("Invalid URL {}: {}", arweave_url, e);
+                    self.logger
+                        .error(format!("Invalid URL {}: {}", arweave_url, e));
                     continue; // Skip this URL and try the next one
                 }
             };
@@ -147,13 +152,14 @@ impl Gateway for ArweaveGateway {
                     return Ok(true); // Return success if the request was successful
                 }
                 Ok(_) => {
-                    eprintln!(
+                    self.logger.error(format!(
                         "Request failed with non-success status for URL: {}",
                         arweave_url
-                    );
+                    ));
                 }
                 Err(e) => {
-                    eprintln!("Error requesting URL {}: {}", arweave_url, e);
+                    self.logger
+                        .error(format!("Error requesting URL {}: {}", arweave_url, e));
                 }
             }
         }
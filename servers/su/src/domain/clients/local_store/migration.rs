@@ -11,6 +11,7 @@ use tokio::time::interval;
 use super::super::super::store::StoreClient;
 use crate::domain::config::AoConfig;
 use crate::domain::core::dal::{DataItem, Message, Process};
+use crate::domain::SuLog;
 
 /*
   This is a migration which moves all data
@@ -38,7 +39,7 @@ pub async fn migrate_to_local() -> io::Result<()> {
             .delete(LAST_PROCESS_ROW_KEY)
             .unwrap();
 
-        println!("Cleared saved keys for last processed rows.");
+        SuLog::init().log("Cleared saved keys for last processed rows.".to_string());
         return Ok(());
     }
 
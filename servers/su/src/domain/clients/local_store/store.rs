@@ -5,7 +5,7 @@ use rocksdb::{Options, DB};
 use tokio::time::{sleep, Duration};
 
 use super::super::super::core::dal::{
-    DataStore, Log, Message, PaginatedMessages, Process, StoreErrorType,
+    DataStore, Log, Message, PaginatedMessages, Process, SaveOutcome, StoreErrorType,
 };
 use super::super::super::SuLog;
 
@@ -23,6 +23,8 @@ pub struct LocalStoreClient {
       and Messages, only public for migration purposes
     */
     pub index_db: DB,
+    default_page_limit: i64,
+    max_page_limit: i64,
 }
 
 impl From<rocksdb::Error> for StoreErrorType {
@@ -63,6 +65,8 @@ impl LocalStoreClient {
             _logger: logger,
             file_db,
             index_db,
+            default_page_limit: 100,
+            max_page_limit: 5000,
         })
     }
 
@@ -101,9 +105,41 @@ impl LocalStoreClient {
             _logger: logger,
             file_db,
             index_db,
+            default_page_limit: 100,
+            max_page_limit: 5000,
         })
     }
 
+    /*
+      Apply DEFAULT_PAGE_LIMIT and MAX_PAGE_LIMIT, the
+      constructors default to 100 and 5000.
+    */
+    pub fn with_page_limits(mut self, default_page_limit: i64, max_page_limit: i64) -> Self {
+        self.default_page_limit = default_page_limit;
+        self.max_page_limit = max_page_limit;
+        self
+    }
+
+    /*
+      Same rules as StoreClient::validate_limit, a missing
+      limit defaults, anything above the max is clamped
+      and non positive limits are rejected.
+    */
+    fn validate_limit(&self, limit: &Option<i32>) -> Result<usize, StoreErrorType> {
+        match limit {
+            None if self.default_page_limit <= 0 => Err(StoreErrorType::InvalidLimit(format!(
+                "DEFAULT_PAGE_LIMIT must be greater than 0, got {}",
+                self.default_page_limit
+            ))),
+            None => Ok(std::cmp::min(self.default_page_limit, self.max_page_limit) as usize),
+            Some(l) if *l <= 0 => Err(StoreErrorType::InvalidLimit(format!(
+                "Limit must be greater than 0, got {}",
+                l
+            ))),
+            Some(l) => Ok(std::cmp::min(*l as i64, self.max_page_limit) as usize),
+        }
+    }
+
     /*
       Generate a column family for each prefix type in the index. This
       allows us to query them all seperately without conflicting results.
@@ -375,9 +411,15 @@ impl DataStore for LocalStoreClient {
       Index and save a process, currently we dont
       use the process index for anything but building
       it here to remain consistent with how messages
-      are saved
+      are saved. Saving the same process again rewrites
+      the same keys, the outcome reflects whether the
+      process index entry was already there.
     */
-    fn save_process(&self, process: &Process, bundle: &[u8]) -> Result<String, StoreErrorType> {
+    fn save_process(
+        &self,
+        process: &Process,
+        bundle: &[u8],
+    ) -> Result<SaveOutcome, StoreErrorType> {
         let process_id = &process.process.process_id;
         let assignment_id = process.assignment_id()?;
 
@@ -386,6 +428,7 @@ impl DataStore for LocalStoreClient {
         })?;
 
         let process_key = self.proc_composite_key(process_id, &assignment_id);
+        let existed = self.index_db.get_cf(cf, process_key.as_bytes())?.is_some();
         self.index_db
             .put_cf(cf, process_key.as_bytes(), assignment_id.as_bytes())?;
 
@@ -400,7 +443,10 @@ impl DataStore for LocalStoreClient {
         let assignment_key = self.proc_assignment_key(&assignment_id);
         self.file_db.put(assignment_key.as_bytes(), bundle)?;
 
-        Ok("Process saved".to_string())
+        match existed {
+            true => Ok(SaveOutcome::AlreadyExists),
+            false => Ok(SaveOutcome::Inserted),
+        }
     }
 
     /*
@@ -459,6 +505,65 @@ impl DataStore for LocalStoreClient {
         Ok("Message saved".to_string())
     }
 
+    /*
+      The index and file dbs have no shared transaction,
+      so a batch is checked up front and then saved one
+      message at a time.
+    */
+    async fn save_messages(&self, batch: &[(Message, Vec<u8>)]) -> Result<usize, StoreErrorType> {
+        for (message, _) in batch.iter() {
+            if message.message.is_some() {
+                self.check_existing_message(&message.message_id()?)?;
+            }
+        }
+
+        for (message, bundle_in) in batch.iter() {
+            self.save_message(message, bundle_in, None).await?;
+        }
+
+        Ok(batch.len())
+    }
+
+    /*
+      Same lookup as get_process but the
+      bundle is never parsed.
+    */
+    fn process_exists(&self, tx_id: &str) -> Result<bool, StoreErrorType> {
+        let assignment_key = self.proc_assignment_key(tx_id);
+        if self.file_db.get(assignment_key.as_bytes())?.is_some() {
+            return Ok(true);
+        }
+
+        let cf = self.index_db.cf_handle("process").ok_or_else(|| {
+            StoreErrorType::DatabaseError("Column family 'process' not found".to_string())
+        })?;
+        let process_key_prefix = format!("process:{}:", tx_id);
+        let mut iter = self
+            .index_db
+            .prefix_iterator_cf(cf, process_key_prefix.as_bytes());
+
+        if let Some(result) = iter.next() {
+            let (_key, assignment_id_bytes) = result?;
+            let assignment_id = String::from_utf8(assignment_id_bytes.to_vec())?;
+            let assignment_key = self.proc_assignment_key(&assignment_id);
+            return Ok(self.file_db.get(assignment_key.as_bytes())?.is_some());
+        }
+
+        Ok(false)
+    }
+
+    async fn get_processes(&self, process_ids: &[String]) -> Result<Vec<Process>, StoreErrorType> {
+        let mut found = vec![];
+        for id in process_ids.iter() {
+            match self.get_process(id).await {
+                Ok(process) => found.push(process),
+                Err(StoreErrorType::NotFound(_)) => (),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(found)
+    }
+
     async fn get_process(&self, tx_id: &str) -> Result<Process, StoreErrorType> {
         let assignment_key = self.proc_assignment_key(tx_id);
         if let Some(process_bundle) = self.file_db.get(assignment_key.as_bytes())? {
@@ -531,6 +636,18 @@ impl DataStore for LocalStoreClient {
         Err(StoreErrorType::NotFound("Message not found".to_string()))
     }
 
+    /*
+      Only the assignment key lookup from get_message,
+      without the fallback to message ids.
+    */
+    fn get_message_by_assignment(&self, assignment_id: &str) -> Result<Message, StoreErrorType> {
+        let assignment_key = self.msg_assignment_key(assignment_id);
+        match self.file_db.get(assignment_key.as_bytes())? {
+            Some(message_bundle) => Ok(Message::from_bytes(message_bundle)?),
+            None => Err(StoreErrorType::NotFound("Message not found".to_string())),
+        }
+    }
+
     fn check_existing_message(&self, message_id: &String) -> Result<(), StoreErrorType> {
         if let Ok(_message) = self.get_message(message_id) {
             Err(StoreErrorType::MessageExists(
@@ -645,7 +762,7 @@ impl DataStore for LocalStoreClient {
         to_nonce: &Option<String>,
     ) -> Result<PaginatedMessages, StoreErrorType> {
         let process_id = &process_in.process.process_id;
-        let limit_val = limit.unwrap_or(100) as usize;
+        let limit_val = self.validate_limit(limit)?;
 
         let mut messages = Vec::new();
         let mut actual_limit = limit_val;
@@ -829,7 +946,7 @@ impl DataStore for LocalStoreClient {
         from: &Option<String>,
         limit: &Option<i32>,
     ) -> Result<(Vec<(String, Vec<u8>)>, bool), StoreErrorType> {
-        let limit_val = limit.unwrap_or(100) as usize;
+        let limit_val = self.validate_limit(limit)?;
         let mut bundles = vec![];
 
         let (paginated_keys, has_next_page) = self
@@ -853,6 +970,62 @@ impl DataStore for LocalStoreClient {
         Ok((bundles, has_next_page))
     }
 
+    /*
+      The local store has no row ids, the cursor is the
+      base64url of the last message_ordering key returned.
+      Those keys are unique and ordered by epoch and nonce
+      so a page boundary can never split a tie.
+    */
+    async fn get_messages_cursor(
+        &self,
+        process_in: &Process,
+        cursor: &Option<String>,
+        limit: &Option<i32>,
+    ) -> Result<(PaginatedMessages, Option<String>), StoreErrorType> {
+        let process_id = &process_in.process.process_id;
+        let limit_val = self.validate_limit(limit)?;
+
+        let after_key = match cursor {
+            Some(c) => Some(String::from_utf8(base64_url::decode(c).map_err(|_| {
+                StoreErrorType::InvalidCursor(format!("Invalid cursor {}", c))
+            })?)?),
+            None => None,
+        };
+
+        let mut messages = Vec::new();
+        let mut actual_limit = limit_val;
+        if cursor.is_none() && process_in.assignment.is_some() {
+            messages.push(Message::from_process(process_in.clone())?);
+            actual_limit = actual_limit.saturating_sub(1);
+        }
+
+        let (all_keys, _) = self
+            .fetch_message_range(process_id, &None, &None, &None)
+            .await?;
+
+        let remaining: Vec<(String, String)> = all_keys
+            .into_iter()
+            .filter(|(key, _)| match &after_key {
+                Some(after) => key > after,
+                None => true,
+            })
+            .collect();
+
+        let has_next_page = remaining.len() > actual_limit;
+        let mut last_key = after_key.clone().unwrap_or_default();
+        for (key, assignment_id) in remaining.into_iter().take(actual_limit) {
+            messages.push(self.get_message(&assignment_id)?);
+            last_key = key;
+        }
+
+        let next_cursor = match has_next_page {
+            true => Some(base64_url::encode(&last_key)),
+            false => None,
+        };
+        let paginated = PaginatedMessages::from_messages(messages, has_next_page, "timestamp")?;
+        Ok((paginated, next_cursor))
+    }
+
     /*
       Retrieve the latest message for a process.
       Currently this is only run once for a process
@@ -881,4 +1054,117 @@ impl DataStore for LocalStoreClient {
 
         Ok(Some(latest_message))
     }
+
+    /*
+      The local store has no row ids to compare against
+      so the head is always treated as modified.
+    */
+    async fn get_latest_message_if_after(
+        &self,
+        process_id: &str,
+        _after_row_id: i32,
+    ) -> Result<Option<Message>, StoreErrorType> {
+        self.get_latest_message(process_id).await
+    }
+
+    /*
+      Walks the ordering keys of the process and picks out
+      the requested nonces, there is no index by nonce.
+    */
+    async fn get_messages_by_nonces(
+        &self,
+        process_id: &str,
+        nonces: &[i32],
+    ) -> Result<Vec<Message>, StoreErrorType> {
+        if nonces.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let (paginated_keys, _) = self
+            .fetch_message_range_nonce(&process_id.to_string(), &None, &None, &None)
+            .await?;
+
+        let mut found: Vec<(i32, Message)> = vec![];
+        for (key, assignment_id) in paginated_keys {
+            let parts: Vec<&str> = key.split(':').collect();
+            if parts.len() < 4 {
+                continue;
+            }
+            let nonce = match parts[3].parse::<i32>() {
+                Ok(n) => n,
+                Err(_) => continue,
+            };
+            if nonces.contains(&nonce) {
+                found.push((nonce, self.get_message(&assignment_id)?));
+            }
+        }
+
+        found.sort_by_key(|(nonce, _)| *nonce);
+        Ok(found.into_iter().map(|(_, message)| message).collect())
+    }
+
+    /*
+      fetch_message_range_nonce treats from as exclusive,
+      so the range is asked for from just before from_nonce.
+    */
+    async fn get_messages_by_nonce(
+        &self,
+        process_id: &str,
+        from_nonce: i32,
+        to_nonce: Option<i32>,
+        limit: Option<i32>,
+    ) -> Result<PaginatedMessages, StoreErrorType> {
+        let limit_val = self.validate_limit(&limit)?;
+
+        let (paginated_keys, _) = self
+            .fetch_message_range_nonce(
+                &process_id.to_string(),
+                &Some(from_nonce.saturating_sub(1).to_string()),
+                &to_nonce.map(|t| t.to_string()),
+                &None,
+            )
+            .await?;
+
+        let mut found: Vec<(i32, String)> = paginated_keys
+            .into_iter()
+            .filter_map(|(key, assignment_id)| {
+                let parts: Vec<&str> = key.split(':').collect();
+                let nonce = parts.get(3)?.parse::<i32>().ok()?;
+                Some((nonce, assignment_id))
+            })
+            .collect();
+        found.sort_by_key(|(nonce, _)| *nonce);
+
+        let has_next_page = found.len() > limit_val;
+        let mut page_messages = vec![];
+        for (_, assignment_id) in found.into_iter().take(limit_val) {
+            page_messages.push(self.get_message(&assignment_id)?);
+        }
+
+        Ok(PaginatedMessages::from_messages(
+            page_messages,
+            has_next_page,
+            "nonce",
+        )?)
+    }
+
+    /*
+      There is no running total in the local store, the
+      bundles of the process are walked and their sizes
+      summed.
+    */
+    async fn get_process_storage_bytes(&self, process_id: &str) -> Result<i64, StoreErrorType> {
+        let (paginated_keys, _) = self
+            .fetch_message_range(&process_id.to_string(), &None, &None, &None)
+            .await?;
+
+        let mut total: i64 = 0;
+        for (_, assignment_id) in paginated_keys {
+            let assignment_key = self.msg_assignment_key(&assignment_id);
+            if let Some(bundle) = self.file_db.get_pinned(assignment_key.as_bytes())? {
+                total += bundle.len() as i64;
+            }
+        }
+        Ok(total)
+    }
 }
@@ -6,6 +6,7 @@ use super::super::gateway::ArweaveGateway;
 use crate::domain::config::AoConfig;
 use crate::domain::core::dal::{DataStore, Gateway, Process, StoreErrorType};
 use crate::domain::flows::msg_deephash;
+use crate::domain::SuLog;
 
 /*
   This program allows us to keep another RocksDB instance
@@ -33,10 +34,11 @@ pub async fn sync_local_drives(interval: u64) -> io::Result<()> {
         .expect("Failed to create LocalStoreClient"),
     );
 
-    println!(
+    let logger = SuLog::init();
+    logger.log(format!(
         "Initialized a write store to sync at {} and {}",
         config.su_file_sync_db_dir, config.su_index_sync_db_dir
-    );
+    ));
 
     loop {
         /*
@@ -121,11 +123,11 @@ pub async fn sync_local_drives(interval: u64) -> io::Result<()> {
                 }
 
                 if messages_fetch.edges.len() > 0 {
-                    println!(
+                    logger.log(format!(
                         "Syncing {} messages for process {} ...",
                         messages_fetch.edges.len(),
                         process_id
-                    );
+                    ));
                 }
 
                 for message in messages_fetch.edges.iter() {
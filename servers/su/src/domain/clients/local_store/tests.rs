@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use super::super::store::LocalStoreClient;
-    use crate::domain::core::dal::{DataStore, Message, Process, StoreErrorType};
+    use crate::domain::core::dal::{DataStore, Message, Process, SaveOutcome, StoreErrorType};
     use base64_url::decode;
     use std::fs;
     use std::path::PathBuf;
@@ -49,9 +49,12 @@ mod tests {
         let process_bundle = create_test_process_bundle();
         let test_process = Process::from_bytes(process_bundle.clone())?;
 
-        client.save_process(&test_process, &process_bundle)?;
+        let first = client.save_process(&test_process, &process_bundle)?;
+        let second = client.save_process(&test_process, &process_bundle)?;
         let retrieved_process = client.get_process(&test_process.process.process_id).await?;
 
+        assert_eq!(first, SaveOutcome::Inserted);
+        assert_eq!(second, SaveOutcome::AlreadyExists);
         assert_eq!(
             retrieved_process.process.process_id,
             test_process.process.process_id
@@ -287,6 +290,50 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_cursor_limit_is_validated() -> Result<(), StoreErrorType> {
+        let test_db = TestDb::new(7);
+        let client = LocalStoreClient::new(&test_db.file_db_path(), &test_db.index_db_path())?
+            .with_page_limits(100, 2);
+
+        let (process_bundle, message_bundles) = bundle_list();
+        let test_process = Process::from_bytes(process_bundle.clone())?;
+        client.save_process(&test_process, &process_bundle)?;
+        for bundle in message_bundles.iter() {
+            let test_message = Message::from_bytes(bundle.clone())?;
+            client.save_message(&test_message, bundle, None).await?;
+        }
+
+        for limit in [0, -1] {
+            let result = client
+                .get_messages_cursor(&test_process, &None, &Some(limit))
+                .await;
+            assert!(matches!(result, Err(StoreErrorType::InvalidLimit(_))));
+            let result = client
+                .get_messages(&test_process, &None, &None, &Some(limit), &None, &None)
+                .await;
+            assert!(matches!(result, Err(StoreErrorType::InvalidLimit(_))));
+            let result = client
+                .get_message_bundles(&test_process, &None, &Some(limit))
+                .await;
+            assert!(matches!(result, Err(StoreErrorType::InvalidLimit(_))));
+        }
+
+        let (clamped, _) = client
+            .get_messages_cursor(&test_process, &None, &Some(50))
+            .await?;
+        assert_eq!(clamped.edges.len(), 2);
+        assert!(clamped.page_info.has_next_page);
+
+        let client = client.with_page_limits(0, 2);
+        let result = client
+            .get_messages_cursor(&test_process, &None, &None)
+            .await;
+        assert!(matches!(result, Err(StoreErrorType::InvalidLimit(_))));
+
+        Ok(())
+    }
+
     /*
       Helper functions to create test data using
       base64_url encoded bundles
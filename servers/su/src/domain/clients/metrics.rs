@@ -1,6 +1,8 @@
 use super::super::config::AoConfig;
-use super::super::core::dal::CoreMetrics;
-use prometheus::{HistogramOpts, HistogramVec, IntCounter, Registry, TextEncoder};
+use super::super::core::dal::{CoreMetrics, StoreMetrics};
+use prometheus::{
+    Gauge, GaugeVec, HistogramOpts, HistogramVec, IntCounter, Opts, Registry, TextEncoder,
+};
 
 /*
   Implementation of metrics
@@ -13,6 +15,16 @@ pub struct PromMetrics {
     enabled: bool,
     core_metrics: HistogramVec,
     message_save_failures: IntCounter,
+    read_deadline_hits: IntCounter,
+    message_cache_hits: IntCounter,
+    message_cache_misses: IntCounter,
+    bytestore_stats: GaugeVec,
+    bytestore_drift_ratio: Gauge,
+    bytestore_retries: IntCounter,
+    messages_saved: IntCounter,
+    messages_read: IntCounter,
+    bytestore_misses: IntCounter,
+    write_queue_dead_letters: Gauge,
     registry: Registry,
 }
 
@@ -45,10 +57,119 @@ impl PromMetrics {
             .register(Box::new(message_save_failures.clone()))
             .unwrap();
 
+        let read_deadline_hits: IntCounter = IntCounter::new(
+            "read_deadline_hits",
+            "count of bytestore reads cut short by the read deadline",
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(read_deadline_hits.clone()))
+            .unwrap();
+
+        let message_cache_hits: IntCounter = IntCounter::new(
+            "message_cache_hits",
+            "count of single message reads served from the message cache",
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(message_cache_hits.clone()))
+            .unwrap();
+
+        let message_cache_misses: IntCounter = IntCounter::new(
+            "message_cache_misses",
+            "count of single message reads not found in the message cache",
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(message_cache_misses.clone()))
+            .unwrap();
+
+        let bytestore_stats = GaugeVec::new(
+            Opts::new(
+                "bytestore_stats",
+                "rocksdb internal stats sampled from the bytestore",
+            ),
+            &["stat"],
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(bytestore_stats.clone()))
+            .unwrap();
+
+        let bytestore_drift_ratio = Gauge::new(
+            "bytestore_drift_ratio",
+            "fraction of recently sampled messages missing from the bytestore",
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(bytestore_drift_ratio.clone()))
+            .unwrap();
+
+        let bytestore_retries: IntCounter = IntCounter::new(
+            "bytestore_retries",
+            "count of rocksdb operations retried after a transient error",
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(bytestore_retries.clone()))
+            .unwrap();
+
+        let messages_saved: IntCounter = IntCounter::new(
+            "messages_saved",
+            "count of messages saved by the data store",
+        )
+        .unwrap();
+
+        registry.register(Box::new(messages_saved.clone())).unwrap();
+
+        let messages_read: IntCounter = IntCounter::new(
+            "messages_read",
+            "count of messages returned in message list pages",
+        )
+        .unwrap();
+
+        registry.register(Box::new(messages_read.clone())).unwrap();
+
+        let bytestore_misses: IntCounter = IntCounter::new(
+            "bytestore_misses",
+            "count of message list binaries missing from the bytestore and read from the database",
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(bytestore_misses.clone()))
+            .unwrap();
+
+        let write_queue_dead_letters = Gauge::new(
+            "write_queue_dead_letters",
+            "count of queued writes postgres rejected, kept in the write queue dead letters",
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(write_queue_dead_letters.clone()))
+            .unwrap();
+
         PromMetrics {
             enabled: config.enable_metrics,
             core_metrics,
             message_save_failures,
+            read_deadline_hits,
+            message_cache_hits,
+            message_cache_misses,
+            bytestore_stats,
+            bytestore_drift_ratio,
+            bytestore_retries,
+            messages_saved,
+            messages_read,
+            bytestore_misses,
+            write_queue_dead_letters,
             registry,
         }
     }
@@ -115,3 +236,49 @@ impl CoreMetrics for PromMetrics {
         self.message_save_failures.inc();
     }
 }
+
+impl StoreMetrics for PromMetrics {
+    fn inc_save(&self) {
+        self.messages_saved.inc();
+    }
+
+    fn inc_read(&self, count: u64) {
+        self.messages_read.inc_by(count);
+    }
+
+    fn inc_bytestore_miss(&self) {
+        self.bytestore_misses.inc();
+    }
+
+    fn bytestore_read_permit_wait_observe(&self, duration: u128) {
+        self.observe_duration("bytestore_read_permit_wait", duration);
+    }
+
+    fn read_deadline_hit(&self) {
+        self.read_deadline_hits.inc();
+    }
+
+    fn message_cache_hit(&self) {
+        self.message_cache_hits.inc();
+    }
+
+    fn message_cache_miss(&self) {
+        self.message_cache_misses.inc();
+    }
+
+    fn bytestore_stat(&self, name: &str, value: f64) {
+        self.bytestore_stats.with_label_values(&[name]).set(value);
+    }
+
+    fn bytestore_drift_ratio(&self, ratio: f64) {
+        self.bytestore_drift_ratio.set(ratio);
+    }
+
+    fn bytestore_retry(&self) {
+        self.bytestore_retries.inc();
+    }
+
+    fn write_queue_dead_letters(&self, count: u64) {
+        self.write_queue_dead_letters.set(count as f64);
+    }
+}
@@ -25,6 +25,9 @@ table! {
         timestamp -> BigInt,
         bundle -> Bytea,
         hash_chain -> Text,
+        content_type -> Nullable<Varchar>,
+        owner -> Nullable<Varchar>,
+        sequence -> Nullable<BigInt>,
     }
 }
 
@@ -24,13 +24,65 @@ pub struct AoConfig {
     */
     pub use_disk: bool,
     pub su_data_dir: String,
+    pub rocksdb_metrics_interval_secs: u64,
+    pub bytestore_drift_sample_size: i64,
+    pub bytestore_drift_interval_secs: u64,
+    pub bytestore_key_format: String,
+    pub bytestore_legacy_key_formats: bool,
+    pub bytestore_dictionary_compression: bool,
+    pub bytestore_dictionary_size: usize,
+    pub bytestore_compression: String,
+    pub bytestore_retry_attempts: u32,
+    pub bytestore_max_concurrent_reads: usize,
+    pub bytestore_read_parallelism: usize,
+    pub bytestore_shards: usize,
+    pub bytestore_secondary_path: Option<String>,
+    pub bytestore_catch_up_interval_secs: u64,
+    pub strict_bytestore: bool,
+    /*
+      Blob files keep large values out of the LSM tree so
+      compaction does not keep rewriting bundles, at the
+      cost of an extra file read per lookup. Turning them
+      off stores everything in regular SST files, which
+      suits stores of mostly small binaries. Values under
+      min_blob_size stay inline in the SST files either
+      way, raising it makes small reads cheaper but puts
+      more data through compaction. Larger blob files mean
+      fewer open files but reclaiming space from deleted
+      binaries waits on a whole file becoming garbage.
+    */
+    pub bytestore_enable_blob_files: bool,
+    pub bytestore_blob_file_size: u64,
+    pub bytestore_min_blob_size: u64,
     pub migration_batch_size: i64,
     pub db_write_connections: u32,
     pub db_read_connections: u32,
+    pub db_min_idle_connections: Option<u32>,
+    pub db_connection_timeout_secs: u64,
+    pub db_checkout_retries: u32,
+    pub db_checkout_backoff_ms: u64,
+    pub db_statement_timeout_ms: Option<u64>,
+    pub db_read_statement_timeout_ms: Option<u64>,
     pub database_url: String,
     pub database_read_url: String,
+    pub db_ssl_mode: Option<String>,
+    pub db_ssl_root_cert: Option<String>,
+    pub skip_schema_check: bool,
+    pub schema_check_timeout_secs: u64,
+    pub enable_process_rewind: bool,
+    pub read_only: bool,
+    pub bundle_compression: Option<String>,
     pub max_read_memory: usize,
+    pub default_page_limit: i64,
+    pub max_page_limit: i64,
+    pub read_binaries_deadline_ms: Option<u64>,
     pub process_cache_size: usize,
+    pub message_cache_size: usize,
+    pub max_message_data_size: usize,
+    pub skip_oversized_message_data: bool,
+    pub use_write_queue: bool,
+    pub write_queue_dir: String,
+    pub write_queue_batch_size: i64,
 
     /*
       These configurations are for the new local_store
@@ -114,6 +166,34 @@ impl AoConfig {
             Ok(val) => val,
             Err(_e) => env::var("DATABASE_URL")?,
         };
+        let db_ssl_mode = match env::var("DB_SSL_MODE") {
+            Ok(val) => Some(val),
+            Err(_e) => None,
+        };
+        let db_ssl_root_cert = match env::var("DB_SSL_ROOT_CERT") {
+            Ok(val) => Some(val),
+            Err(_e) => None,
+        };
+        let skip_schema_check = match env::var("SKIP_SCHEMA_CHECK") {
+            Ok(val) => val == "true",
+            Err(_e) => false,
+        };
+        let schema_check_timeout_secs = match env::var("SCHEMA_CHECK_TIMEOUT_SECS") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 60,
+        };
+        let bundle_compression = match env::var("BUNDLE_COMPRESSION") {
+            Ok(val) => Some(val),
+            Err(_e) => None,
+        };
+        let enable_process_rewind = match env::var("ENABLE_PROCESS_REWIND") {
+            Ok(val) => val == "true",
+            Err(_e) => false,
+        };
+        let read_only = match env::var("READ_ONLY") {
+            Ok(val) => val == "true",
+            Err(_e) => false,
+        };
         let use_disk = match env::var("USE_DISK") {
             Ok(val) => val == "true",
             Err(_e) => false,
@@ -122,6 +202,78 @@ impl AoConfig {
             true => env::var("SU_DATA_DIR")?,
             false => "".to_string(),
         };
+        let rocksdb_metrics_interval_secs = match env::var("ROCKSDB_METRICS_INTERVAL_SECS") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 60,
+        };
+        let bytestore_drift_sample_size = match env::var("BYTESTORE_DRIFT_SAMPLE_SIZE") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 100,
+        };
+        let bytestore_drift_interval_secs = match env::var("BYTESTORE_DRIFT_INTERVAL_SECS") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 300,
+        };
+        let bytestore_key_format = match env::var("BYTESTORE_KEY_FORMAT") {
+            Ok(val) => val,
+            Err(_e) => "text".to_string(),
+        };
+        let bytestore_legacy_key_formats = match env::var("BYTESTORE_LEGACY_KEY_FORMATS") {
+            Ok(val) => val == "true",
+            Err(_e) => false,
+        };
+        let bytestore_dictionary_compression = match env::var("BYTESTORE_DICTIONARY_COMPRESSION") {
+            Ok(val) => val == "true",
+            Err(_e) => false,
+        };
+        let bytestore_compression = match env::var("BYTESTORE_COMPRESSION") {
+            Ok(val) => val,
+            Err(_e) => "none".to_string(),
+        };
+        let bytestore_enable_blob_files = match env::var("BYTESTORE_ENABLE_BLOB_FILES") {
+            Ok(val) => val == "true",
+            Err(_e) => true,
+        };
+        let bytestore_blob_file_size = match env::var("BYTESTORE_BLOB_FILE_SIZE") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 5 * 1024 * 1024 * 1024,
+        };
+        let bytestore_min_blob_size = match env::var("BYTESTORE_MIN_BLOB_SIZE") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 1024,
+        };
+        let bytestore_dictionary_size = match env::var("BYTESTORE_DICTIONARY_SIZE") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 112640,
+        };
+        let bytestore_retry_attempts = match env::var("BYTESTORE_RETRY_ATTEMPTS") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 3,
+        };
+        let bytestore_max_concurrent_reads = match env::var("BYTESTORE_MAX_CONCURRENT_READS") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 64,
+        };
+        let bytestore_read_parallelism = match env::var("BYTESTORE_READ_PARALLELISM") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 4,
+        };
+        let bytestore_shards = match env::var("BYTESTORE_SHARDS") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 1,
+        };
+        let bytestore_secondary_path = match env::var("BYTESTORE_SECONDARY_PATH") {
+            Ok(val) => Some(val),
+            Err(_e) => None,
+        };
+        let bytestore_catch_up_interval_secs = match env::var("BYTESTORE_CATCH_UP_INTERVAL_SECS") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 5,
+        };
+        let strict_bytestore = match env::var("STRICT_BYTESTORE") {
+            Ok(val) => val == "true",
+            Err(_e) => false,
+        };
         let migration_batch_size = match env::var("MIGRATION_BATCH_SIZE") {
             Ok(val) => val.parse().unwrap(),
             Err(_e) => 1000,
@@ -134,6 +286,30 @@ impl AoConfig {
             Ok(val) => val.parse().unwrap(),
             Err(_e) => 10,
         };
+        let db_min_idle_connections = match env::var("DB_MIN_IDLE_CONNECTIONS") {
+            Ok(val) => Some(val.parse().unwrap()),
+            Err(_e) => None,
+        };
+        let db_connection_timeout_secs = match env::var("DB_CONNECTION_TIMEOUT_SECS") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 30,
+        };
+        let db_checkout_retries = match env::var("DB_CHECKOUT_RETRIES") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 2,
+        };
+        let db_checkout_backoff_ms = match env::var("DB_CHECKOUT_BACKOFF_MS") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 50,
+        };
+        let db_statement_timeout_ms = match env::var("DB_STATEMENT_TIMEOUT_MS") {
+            Ok(val) => Some(val.parse().unwrap()),
+            Err(_e) => None,
+        };
+        let db_read_statement_timeout_ms = match env::var("DB_READ_STATEMENT_TIMEOUT_MS") {
+            Ok(val) => Some(val.parse().unwrap()),
+            Err(_e) => db_statement_timeout_ms,
+        };
         let graphql_url = match env::var("GRAPHQL_URL") {
             Ok(val) => val,
             Err(_e) => env::var("GATEWAY_URL")?,
@@ -150,10 +326,46 @@ impl AoConfig {
             Ok(val) => val.parse().unwrap(),
             Err(_e) => 1_073_741_824,
         };
+        let default_page_limit = match env::var("DEFAULT_PAGE_LIMIT") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 100,
+        };
+        let max_page_limit = match env::var("MAX_PAGE_LIMIT") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 5000,
+        };
+        let read_binaries_deadline_ms = match env::var("READ_BINARIES_DEADLINE_MS") {
+            Ok(val) => Some(val.parse().unwrap()),
+            Err(_e) => None,
+        };
         let process_cache_size = match env::var("PROCESS_CACHE_SIZE") {
             Ok(val) => val.parse().unwrap(),
             Err(_e) => 20000,
         };
+        let message_cache_size = match env::var("MESSAGE_CACHE_SIZE") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 1000,
+        };
+        let max_message_data_size = match env::var("MAX_MESSAGE_DATA_SIZE") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 104_857_600,
+        };
+        let skip_oversized_message_data = match env::var("SKIP_OVERSIZED_MESSAGE_DATA") {
+            Ok(val) => val == "true",
+            Err(_e) => false,
+        };
+        let use_write_queue = match env::var("USE_WRITE_QUEUE") {
+            Ok(val) => val == "true",
+            Err(_e) => false,
+        };
+        let write_queue_dir = match use_write_queue {
+            true => env::var("WRITE_QUEUE_DIR")?,
+            false => "".to_string(),
+        };
+        let write_queue_batch_size = match env::var("WRITE_QUEUE_BATCH_SIZE") {
+            Ok(val) => val.parse().unwrap(),
+            Err(_e) => 500,
+        };
         let enable_process_assignment = match env::var("ENABLE_PROCESS_ASSIGNMENT") {
             Ok(val) => val == "true",
             Err(_e) => false,
@@ -189,6 +401,13 @@ impl AoConfig {
         Ok(AoConfig {
             database_url: env::var("DATABASE_URL")?,
             database_read_url,
+            db_ssl_mode,
+            db_ssl_root_cert,
+            skip_schema_check,
+            schema_check_timeout_secs,
+            enable_process_rewind,
+            read_only,
+            bundle_compression,
             su_wallet_path: env::var("SU_WALLET_PATH")?,
             graphql_url,
             arweave_url,
@@ -197,12 +416,45 @@ impl AoConfig {
             scheduler_list_path: env::var("SCHEDULER_LIST_PATH")?,
             use_disk,
             su_data_dir,
+            rocksdb_metrics_interval_secs,
+            bytestore_drift_sample_size,
+            bytestore_drift_interval_secs,
+            bytestore_key_format,
+            bytestore_legacy_key_formats,
+            bytestore_dictionary_compression,
+            bytestore_dictionary_size,
+            bytestore_compression,
+            bytestore_retry_attempts,
+            bytestore_max_concurrent_reads,
+            bytestore_read_parallelism,
+            bytestore_shards,
+            bytestore_secondary_path,
+            bytestore_catch_up_interval_secs,
+            strict_bytestore,
+            bytestore_enable_blob_files,
+            bytestore_blob_file_size,
+            bytestore_min_blob_size,
             migration_batch_size,
             db_write_connections,
             db_read_connections,
+            db_min_idle_connections,
+            db_connection_timeout_secs,
+            db_checkout_retries,
+            db_checkout_backoff_ms,
+            db_statement_timeout_ms,
+            db_read_statement_timeout_ms,
             enable_metrics,
             max_read_memory,
+            default_page_limit,
+            max_page_limit,
+            read_binaries_deadline_ms,
             process_cache_size,
+            message_cache_size,
+            max_message_data_size,
+            skip_oversized_message_data,
+            use_write_queue,
+            write_queue_dir,
+            write_queue_batch_size,
             enable_process_assignment,
             arweave_url_list,
             use_local_store,
@@ -277,6 +277,54 @@ impl From<u16> for SignerMap {
     }
 }
 
+pub const CONTENT_TYPE_ANS104_BUNDLE: &str = "application/x-ans104-bundle";
+pub const CONTENT_TYPE_ANS104_DATA_ITEM: &str = "application/x-ans104-data-item";
+pub const CONTENT_TYPE_JSON: &str = "application/json";
+pub const CONTENT_TYPE_UNKNOWN: &str = "application/octet-stream";
+
+fn is_known_signature_type(bytes: &[u8]) -> bool {
+    if bytes.len() < 2 {
+        return false;
+    }
+    SignerMap::from(u16::from_le_bytes([bytes[0], bytes[1]])) != SignerMap::None
+}
+
+/*
+  Best effort detection of what a stored bundle is by
+  its leading bytes. An ANS-104 bundle starts with a 32
+  byte item count followed by 64 byte headers and then
+  the first data item, a bare data item starts with its
+  2 byte signature type.
+*/
+pub fn sniff_content_type(bytes: &[u8]) -> &'static str {
+    if bytes.len() >= 32 {
+        if let Ok(items_len) = _32_byte_array_to_long(&bytes[0..32]) {
+            let first_item_offset = (items_len as usize)
+                .checked_mul(64)
+                .and_then(|h| h.checked_add(32));
+            if let Some(offset) = first_item_offset {
+                if items_len > 0
+                    && bytes[8..32].iter().all(|b| *b == 0)
+                    && bytes.len() > offset
+                    && is_known_signature_type(&bytes[offset..])
+                {
+                    return CONTENT_TYPE_ANS104_BUNDLE;
+                }
+            }
+        }
+    }
+
+    if is_known_signature_type(bytes) && DataItem::from_bytes(bytes.to_vec()).is_ok() {
+        return CONTENT_TYPE_ANS104_DATA_ITEM;
+    }
+
+    if serde_json::from_slice::<serde_json::de::IgnoredAny>(bytes).is_ok() {
+        return CONTENT_TYPE_JSON;
+    }
+
+    CONTENT_TYPE_UNKNOWN
+}
+
 pub const LIST_AS_BUFFER: &[u8] = "list".as_bytes();
 pub const BLOB_AS_BUFFER: &[u8] = "blob".as_bytes();
 pub const DATAITEM_AS_BUFFER: &[u8] = "dataitem".as_bytes();
@@ -935,4 +983,26 @@ mod tests {
         let bundle_bytes = data_bundle.to_bytes();
         assert!(bundle_bytes.is_ok(), "Bundling failed");
     }
+
+    #[test]
+    fn test_sniff_content_type() {
+        let item_bytes =
+            base64_url::decode(&ITEM_STR.to_string()).expect("failed to encode data item");
+        let data_item =
+            DataItem::from_bytes(item_bytes.clone()).expect("failed to build data item");
+        let mut data_bundle = DataBundle::new();
+        data_bundle.add_item(data_item);
+        let bundle_bytes = data_bundle.to_bytes().expect("failed to bundle");
+
+        assert_eq!(
+            sniff_content_type(&bundle_bytes),
+            CONTENT_TYPE_ANS104_BUNDLE
+        );
+        assert_eq!(
+            sniff_content_type(&item_bytes),
+            CONTENT_TYPE_ANS104_DATA_ITEM
+        );
+        assert_eq!(sniff_content_type(b"{\"a\": 1}"), CONTENT_TYPE_JSON);
+        assert_eq!(sniff_content_type(b"not json"), CONTENT_TYPE_UNKNOWN);
+    }
 }
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 
-pub use super::bytes::DataItem;
+pub use super::bytes::{sniff_content_type, DataItem};
 pub use super::json::{JsonErrorType, Message, PaginatedMessages, Process};
 pub use super::router::{ProcessScheduler, Scheduler};
 pub use super::tags::Tag;
@@ -98,6 +98,36 @@ pub enum StoreErrorType {
     EnvVarError(String),
     IntError(String),
     MessageExists(String),
+    DataTooLarge(String),
+    NonceConflict(String),
+    InvalidLimit(String),
+    Cancelled(String),
+    Forbidden(String),
+    InvalidCursor(String),
+    SerializationFailed(String),
+    InvalidRange(String),
+    ReadOnly(String),
+    ByteStoreError(String),
+}
+
+/*
+  Result of an insert that is a no-op when the row
+  already exists. Display keeps the "saved" string the
+  save methods used to return.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOutcome {
+    Inserted,
+    AlreadyExists,
+}
+
+impl std::fmt::Display for SaveOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveOutcome::Inserted => write!(f, "saved"),
+            SaveOutcome::AlreadyExists => write!(f, "already exists"),
+        }
+    }
 }
 
 impl From<serde_json::Error> for StoreErrorType {
@@ -126,14 +156,21 @@ impl From<std::string::FromUtf8Error> for StoreErrorType {
 
 #[async_trait]
 pub trait DataStore: Send + Sync {
-    fn save_process(&self, process: &Process, bundle_in: &[u8]) -> Result<String, StoreErrorType>;
+    fn save_process(
+        &self,
+        process: &Process,
+        bundle_in: &[u8],
+    ) -> Result<SaveOutcome, StoreErrorType>;
     async fn get_process(&self, process_id_in: &str) -> Result<Process, StoreErrorType>;
+    async fn get_processes(&self, process_ids: &[String]) -> Result<Vec<Process>, StoreErrorType>;
+    fn process_exists(&self, process_id_in: &str) -> Result<bool, StoreErrorType>;
     async fn save_message(
         &self,
         message: &Message,
         bundle_in: &[u8],
         deep_hash: Option<&String>,
     ) -> Result<String, StoreErrorType>;
+    async fn save_messages(&self, batch: &[(Message, Vec<u8>)]) -> Result<usize, StoreErrorType>;
     async fn get_messages(
         &self,
         process: &Process,
@@ -143,6 +180,12 @@ pub trait DataStore: Send + Sync {
         from_nonce: &Option<String>,
         to_nonce: &Option<String>,
     ) -> Result<PaginatedMessages, StoreErrorType>;
+    async fn get_messages_cursor(
+        &self,
+        process: &Process,
+        cursor: &Option<String>,
+        limit: &Option<i32>,
+    ) -> Result<(PaginatedMessages, Option<String>), StoreErrorType>;
     async fn get_message_bundles(
         &self,
         process: &Process,
@@ -150,10 +193,29 @@ pub trait DataStore: Send + Sync {
         limit: &Option<i32>,
     ) -> Result<(Vec<(String, Vec<u8>)>, bool), StoreErrorType>;
     fn get_message(&self, message_id_in: &str) -> Result<Message, StoreErrorType>;
+    fn get_message_by_assignment(&self, assignment_id_in: &str) -> Result<Message, StoreErrorType>;
     async fn get_latest_message(
         &self,
         process_id_in: &str,
     ) -> Result<Option<Message>, StoreErrorType>;
+    async fn get_latest_message_if_after(
+        &self,
+        process_id_in: &str,
+        after_row_id: i32,
+    ) -> Result<Option<Message>, StoreErrorType>;
+    async fn get_messages_by_nonces(
+        &self,
+        process_id_in: &str,
+        nonces: &[i32],
+    ) -> Result<Vec<Message>, StoreErrorType>;
+    async fn get_messages_by_nonce(
+        &self,
+        process_id_in: &str,
+        from_nonce: i32,
+        to_nonce: Option<i32>,
+        limit: Option<i32>,
+    ) -> Result<PaginatedMessages, StoreErrorType>;
+    async fn get_process_storage_bytes(&self, process_id_in: &str) -> Result<i64, StoreErrorType>;
     fn check_existing_message(&self, message_id: &String) -> Result<(), StoreErrorType>;
     async fn check_existing_deep_hash(
         &self,
@@ -178,16 +240,25 @@ pub trait RouterDataStore: Send + Sync {
     fn save_process_scheduler(
         &self,
         process_scheduler: &ProcessScheduler,
-    ) -> Result<String, StoreErrorType>;
+    ) -> Result<SaveOutcome, StoreErrorType>;
     fn get_process_scheduler(
         &self,
         process_id_in: &str,
     ) -> Result<ProcessScheduler, StoreErrorType>;
-    fn save_scheduler(&self, scheduler: &Scheduler) -> Result<String, StoreErrorType>;
+    fn update_process_scheduler(
+        &self,
+        process_scheduler: &ProcessScheduler,
+    ) -> Result<String, StoreErrorType>;
+    fn delete_process_scheduler(&self, process_id_in: &str) -> Result<(), StoreErrorType>;
+    fn save_scheduler(&self, scheduler: &Scheduler) -> Result<SaveOutcome, StoreErrorType>;
     fn update_scheduler(&self, scheduler: &Scheduler) -> Result<String, StoreErrorType>;
     fn get_scheduler(&self, row_id_in: &i32) -> Result<Scheduler, StoreErrorType>;
     fn get_scheduler_by_url(&self, url_in: &String) -> Result<Scheduler, StoreErrorType>;
     fn get_all_schedulers(&self) -> Result<Vec<Scheduler>, StoreErrorType>;
+    fn get_processes_for_scheduler(
+        &self,
+        scheduler_row_id: i32,
+    ) -> Result<Vec<String>, StoreErrorType>;
 }
 
 pub struct MockRouterDataStore;
@@ -197,7 +268,7 @@ impl RouterDataStore for MockRouterDataStore {
     fn save_process_scheduler(
         &self,
         _process_scheduler: &ProcessScheduler,
-    ) -> Result<String, StoreErrorType> {
+    ) -> Result<SaveOutcome, StoreErrorType> {
         unreachable!("save_process_scheduler is not implemented in MockRouterDataStore");
     }
 
@@ -208,7 +279,18 @@ impl RouterDataStore for MockRouterDataStore {
         unreachable!("get_process_scheduler is not implemented in MockRouterDataStore");
     }
 
-    fn save_scheduler(&self, _scheduler: &Scheduler) -> Result<String, StoreErrorType> {
+    fn update_process_scheduler(
+        &self,
+        _process_scheduler: &ProcessScheduler,
+    ) -> Result<String, StoreErrorType> {
+        unreachable!("update_process_scheduler is not implemented in MockRouterDataStore");
+    }
+
+    fn delete_process_scheduler(&self, _process_id_in: &str) -> Result<(), StoreErrorType> {
+        unreachable!("delete_process_scheduler is not implemented in MockRouterDataStore");
+    }
+
+    fn save_scheduler(&self, _scheduler: &Scheduler) -> Result<SaveOutcome, StoreErrorType> {
         unreachable!("save_scheduler is not implemented in MockRouterDataStore");
     }
 
@@ -227,6 +309,13 @@ impl RouterDataStore for MockRouterDataStore {
     fn get_all_schedulers(&self) -> Result<Vec<Scheduler>, StoreErrorType> {
         unreachable!("get_all_schedulers is not implemented in MockRouterDataStore");
     }
+
+    fn get_processes_for_scheduler(
+        &self,
+        _scheduler_row_id: i32,
+    ) -> Result<Vec<String>, StoreErrorType> {
+        unreachable!("get_processes_for_scheduler is not implemented in MockRouterDataStore");
+    }
 }
 
 pub trait CoreMetrics: Send + Sync {
@@ -239,3 +328,27 @@ pub trait CoreMetrics: Send + Sync {
     fn acquire_write_lock_observe(&self, duration: u128);
     fn failed_message_save(&self);
 }
+
+/*
+  Counters for store operations. Every method defaults
+  to a no-op so an implementation only overrides the
+  counters it cares about, set it on the StoreClient
+  with with_store_metrics.
+*/
+pub trait StoreMetrics: Send + Sync {
+    fn inc_save(&self) {}
+    fn inc_read(&self, _count: u64) {}
+    fn inc_bytestore_miss(&self) {}
+    fn bytestore_read_permit_wait_observe(&self, _duration: u128) {}
+    fn read_deadline_hit(&self) {}
+    fn message_cache_hit(&self) {}
+    fn message_cache_miss(&self) {}
+    fn bytestore_stat(&self, _name: &str, _value: f64) {}
+    fn bytestore_drift_ratio(&self, _ratio: f64) {}
+    fn bytestore_retry(&self) {}
+    fn write_queue_dead_letters(&self, _count: u64) {}
+}
+
+pub struct NoopStoreMetrics;
+
+impl StoreMetrics for NoopStoreMetrics {}
@@ -99,6 +99,12 @@ pub struct AssignmentInner {
 pub struct Message {
     pub message: Option<MessageInner>,
     pub assignment: AssignmentInner,
+    /*
+      Detected format of the stored bundle, only set
+      when a Message is read back out of the data store
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -110,6 +116,14 @@ pub struct PaginatedMessages {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PageInfo {
     pub has_next_page: bool,
+    /*
+      Only set when the caller asked for it, counting
+      every matching message costs an extra query.
+    */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -520,6 +534,7 @@ impl Message {
                 Ok(Message {
                     message: message_inner,
                     assignment,
+                    content_type: None,
                 })
             }
         }
@@ -601,6 +616,7 @@ impl Message {
         Ok(Message {
             message: message_inner,
             assignment: assignment_inner,
+            content_type: None,
         })
     }
 
@@ -633,6 +649,7 @@ impl Message {
         Ok(Message {
             message: message_inner,
             assignment: assignment_inner,
+            content_type: None,
         })
     }
 
@@ -699,6 +716,29 @@ impl Message {
         Ok(message_tag.value.clone())
     }
 
+    /*
+      Address of the sender, assignment only
+      messages have no sender of their own.
+    */
+    pub fn owner(&self) -> Option<String> {
+        self.message
+            .as_ref()
+            .map(|message| message.owner.address.clone())
+    }
+
+    /*
+      External sequence some deployments assign in a
+      Sequence tag on the assignment, independent of the
+      nonce and timestamp.
+    */
+    pub fn sequence(&self) -> Option<i64> {
+        self.assignment
+            .tags
+            .iter()
+            .find(|tag| tag.name == "Sequence")
+            .and_then(|tag| tag.value.parse::<i64>().ok())
+    }
+
     pub fn assignment_id(&self) -> Result<String, JsonErrorType> {
         let assignment_id = self.assignment.id.clone();
         Ok(assignment_id)
@@ -788,6 +828,7 @@ impl Message {
                 Ok(Message {
                     message,
                     assignment,
+                    content_type: None,
                 })
             }
         }
@@ -837,7 +878,11 @@ impl PaginatedMessages {
         has_next_page: bool,
         sequence_mode: &'static str,
     ) -> Result<Self, JsonErrorType> {
-        let page_info = PageInfo { has_next_page };
+        let page_info = PageInfo {
+            has_next_page,
+            total_count: None,
+            limit: None,
+        };
 
         let edges = messages
             .into_iter()
@@ -931,4 +976,21 @@ mod tests {
             "boxXWZqkBaZmOKJ3Vh7PZzC07Q9OXmxF4QT_ikodfNY".to_string()
         );
     }
+
+    #[test]
+    fn test_page_info_omits_unset_counts() {
+        let page = PaginatedMessages::from_messages(vec![], false, "timestamp")
+            .expect("failed to build page");
+        let json = serde_json::to_string(&page.page_info).expect("failed to serialize");
+        assert_eq!(json, r#"{"has_next_page":false}"#);
+
+        let mut page = page;
+        page.page_info.total_count = Some(3);
+        page.page_info.limit = Some(100);
+        let json = serde_json::to_string(&page.page_info).expect("failed to serialize");
+        assert_eq!(
+            json,
+            r#"{"has_next_page":false,"total_count":3,"limit":100}"#
+        );
+    }
 }
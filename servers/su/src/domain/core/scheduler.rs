@@ -265,7 +265,7 @@ impl DecodeHash for [u8; 32] {
     }
 }
 
-fn gen_hash_chain(
+pub fn gen_hash_chain(
     previous_or_seed: &str,
     previous_message_id: Option<&str>,
 ) -> Result<String, String> {
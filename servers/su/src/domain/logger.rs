@@ -10,9 +10,15 @@ pub struct SuLog;
 static INIT: Once = Once::new();
 
 impl SuLog {
+    /*
+      The cli may already have installed a tracing
+      subscriber, which also takes over the log crate,
+      in that case log output goes through it instead.
+    */
     pub fn init() -> Arc<dyn Log> {
         INIT.call_once(|| {
-            env_logger::init_from_env(Env::default().default_filter_or("info"));
+            let _ =
+                env_logger::Builder::from_env(Env::default().default_filter_or("info")).try_init();
         });
         Arc::new(SuLog {})
     }
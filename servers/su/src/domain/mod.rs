@@ -1,4 +1,5 @@
 use core::dal::RouterDataStore;
+use std::io;
 use std::sync::Arc;
 
 use tokio::task::spawn_blocking;
@@ -24,19 +25,45 @@ pub use core::router;
 pub use flows::Deps;
 pub use local_store::migration::migrate_to_local;
 pub use local_store::sync_local::sync_local_drives;
+pub use store::backfill_owners;
+pub use store::export_process;
 pub use store::migrate_to_disk;
-
-pub async fn init_deps(mode: Option<String>) -> (Arc<Deps>, Arc<PromMetrics>) {
+pub use store::train_dictionary;
+
+/*
+  Build the dependencies of the server. Startup checks
+  that a correctly configured su can fail are returned
+  as an error for main to exit with, anything else
+  still panics.
+*/
+pub async fn init_deps(mode: Option<String>) -> io::Result<(Arc<Deps>, Arc<PromMetrics>)> {
     let logger: Arc<dyn Log> = SuLog::init();
 
     let config = Arc::new(AoConfig::new(mode.clone()).expect("Failed to read configuration"));
 
+    let metrics = Arc::new(PromMetrics::new(
+        AoConfig::new(mode).expect("Failed to read configuration"),
+    ));
+    let metrics_clone = metrics.clone();
+
     let data_store = if !config.use_local_store {
-        let ds = Arc::new(store::StoreClient::new().expect("Failed to create StoreClient"));
+        let ds = Arc::new(
+            store::StoreClient::new()
+                .expect("Failed to create StoreClient")
+                .with_store_metrics(metrics.clone()),
+        );
         match ds.run_migrations() {
             Ok(m) => logger.log(m),
             Err(e) => logger.log(format!("{:?}", e)),
         }
+        if let Err(e) = ds.apply_bundle_compression() {
+            logger.error(format!("Failed to apply bundle compression: {:?}", e));
+        }
+        ds.wait_for_schema_fingerprint(std::time::Duration::from_secs(
+            config.schema_check_timeout_secs,
+        ))
+        .await
+        .map_err(|e| io::Error::other(format!("Read and write databases do not match: {:?}", e)))?;
         Some(ds)
     } else {
         None
@@ -54,7 +81,8 @@ pub async fn init_deps(mode: Option<String>) -> (Arc<Deps>, Arc<PromMetrics>) {
                 &config.su_file_db_dir,
                 &config.su_index_db_dir,
             )
-            .expect("Failed to create LocalStoreClient"),
+            .expect("Failed to create LocalStoreClient")
+            .with_page_limits(config.default_page_limit, config.max_page_limit),
         ) as Arc<dyn DataStore>
     } else {
         data_store.clone().unwrap().clone()
@@ -68,15 +96,48 @@ pub async fn init_deps(mode: Option<String>) -> (Arc<Deps>, Arc<PromMetrics>) {
           call spawn_blocking or the server wont start until
           its complete and we want to do it in the background
         */
-        spawn_blocking(move || {
-            if let Err(e) = d_clone.sync_bytestore() {
-                logger_clone.log(format!("Failed to migrate tail messages: {:?}", e));
-            } else {
+        spawn_blocking(move || match d_clone.sync_bytestore() {
+            Ok(report) if report.errors > 0 => {
+                logger_clone.log(format!(
+                    "Failed to migrate {} tail messages, synced {}",
+                    report.errors, report.synced
+                ));
+            }
+            Ok(_) => {
                 logger_clone.log("Successfully migrated tail messages".to_string());
             }
+            Err(e) => {
+                logger_clone.log(format!("Failed to migrate tail messages: {:?}", e));
+            }
         });
     }
 
+    if config.use_disk && config.bytestore_secondary_path.is_some() && config.mode != "router" {
+        if let Some(ds) = data_store.clone() {
+            ds.spawn_bytestore_catch_up(std::time::Duration::from_secs(
+                config.bytestore_catch_up_interval_secs,
+            ));
+        }
+    }
+
+    if config.use_disk && config.enable_metrics && config.mode != "router" {
+        if let Some(ds) = data_store.clone() {
+            ds.spawn_rocksdb_metrics(std::time::Duration::from_secs(
+                config.rocksdb_metrics_interval_secs,
+            ));
+            ds.spawn_bytestore_drift_sampler(
+                std::time::Duration::from_secs(config.bytestore_drift_interval_secs),
+                config.bytestore_drift_sample_size,
+            );
+        }
+    }
+
+    if let Some(ds) = data_store.clone() {
+        if config.use_write_queue {
+            spawn_blocking(move || ds.run_write_queue_committer());
+        }
+    }
+
     let scheduler_deps = Arc::new(core::scheduler::SchedulerDeps {
         data_store: main_data_store.clone(),
         logger: logger.clone(),
@@ -98,14 +159,9 @@ pub async fn init_deps(mode: Option<String>) -> (Arc<Deps>, Arc<PromMetrics>) {
         UploaderClient::new(&config.upload_node_url, logger.clone()).expect("Invalid uploader url"),
     );
 
-    let metrics = Arc::new(PromMetrics::new(
-        AoConfig::new(mode).expect("Failed to read configuration"),
-    ));
-    let metrics_clone = metrics.clone();
-
     let deephash_locks = Arc::new(DashMap::new());
 
-    (
+    Ok((
         Arc::new(Deps {
             data_store: main_data_store,
             router_data_store,
@@ -120,5 +176,5 @@ pub async fn init_deps(mode: Option<String>) -> (Arc<Deps>, Arc<PromMetrics>) {
             deephash_locks,
         }),
         metrics_clone,
-    )
+    ))
 }
@@ -310,7 +310,7 @@ async fn main() -> io::Result<()> {
         .expect("Time went backwards")
         .as_secs();
 
-    let (deps, metrics) = init_deps(mode).await;
+    let (deps, metrics) = init_deps(mode).await?;
     let app_state = web::Data::new(AppState {
         deps,
         metrics,
@@ -15,6 +15,11 @@ diesel::table! {
         hash_chain -> Text,
         #[max_length = 255]
         assignment_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        content_type -> Nullable<Varchar>,
+        #[max_length = 255]
+        owner -> Nullable<Varchar>,
+        sequence -> Nullable<Int8>,
     }
 }
 
@@ -40,6 +45,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    rewound_messages (row_id) {
+        row_id -> Int4,
+        #[max_length = 255]
+        process_id -> Varchar,
+        rewound_at -> Timestamp,
+        message -> Jsonb,
+    }
+}
+
 diesel::table! {
     schedulers (row_id) {
         row_id -> Int4,
@@ -57,5 +72,6 @@ diesel::allow_tables_to_appear_in_same_query!(
     messages,
     process_schedulers,
     processes,
+    rewound_messages,
     schedulers,
 );